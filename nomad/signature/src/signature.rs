@@ -1,7 +1,15 @@
 // Code adapted from: https://github.com/gakonst/ethers-rs/blob/master/ethers-core/src/types/signature.rs
 
 use crate::utils::hash_message;
-use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use alloc::{
+	borrow::ToOwned,
+	collections::BTreeMap,
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+#[cfg(test)]
+use alloc::vec;
 use codec::{Decode, Encode};
 use core::convert::TryFrom;
 use elliptic_curve::{consts::U32, sec1::ToEncodedPoint as _};
@@ -10,7 +18,7 @@ use generic_array::GenericArray;
 use k256::{
 	ecdsa::{
 		recoverable::{Id as RecoveryId, Signature as RecoverableSignature},
-		Error as K256SignatureError, Signature as K256Signature,
+		Error as K256SignatureError, Signature as K256Signature, SigningKey as K256SigningKey,
 	},
 	PublicKey as K256PublicKey,
 };
@@ -24,6 +32,8 @@ use thiserror_no_std::Error;
 
 #[cfg(feature = "std")]
 use core::{fmt, str::FromStr};
+#[cfg(feature = "std")]
+use elliptic_curve::rand_core::OsRng;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -48,8 +58,32 @@ pub enum SignatureError {
 	/// Error in recovering public key from signature
 	#[error("Public key recovery error")]
 	RecoveryError,
+	/// Thrown by `recover_strict` when `s` is not in the lower half of the
+	/// secp256k1 group order, i.e. the signature is malleable (EIP-2).
+	#[error("signature is not in canonical low-S form")]
+	InvalidS,
+	/// Thrown when hashing an EIP-712 `TypedData` value that does not match
+	/// its declared type schema.
+	#[error("invalid EIP-712 typed data: {0}")]
+	InvalidTypedData(String),
 }
 
+/// The order `n` of the secp256k1 group.
+const SECP256K1_N: U256 = U256([
+	0xbfd25e8cd0364141,
+	0xbaaedce6af48a03b,
+	0xfffffffffffffffe,
+	0xffffffffffffffff,
+]);
+
+/// `n / 2`, the upper bound (inclusive) for a canonical, EIP-2 low-S value.
+const SECP256K1_N_HALF: U256 = U256([
+	0xdfe92f46681b20a0,
+	0x5d576e7357a4501d,
+	0xffffffffffffffff,
+	0x7fffffffffffffff,
+]);
+
 /// Recovery message data.
 ///
 /// The message data can either be a binary message that is first hashed
@@ -61,6 +95,14 @@ pub enum RecoveryMessage {
 	Data(Vec<u8>),
 	/// Message hash
 	Hash(H256),
+	/// EIP-712 typed structured data
+	TypedData(TypedData),
+}
+
+impl From<TypedData> for RecoveryMessage {
+	fn from(typed_data: TypedData) -> Self {
+		RecoveryMessage::TypedData(typed_data)
+	}
 }
 
 /// An ECDSA signature
@@ -112,18 +154,43 @@ impl Signature {
 		let message_hash = match message {
 			RecoveryMessage::Data(ref message) => hash_message(message),
 			RecoveryMessage::Hash(hash) => hash,
+			RecoveryMessage::TypedData(ref typed_data) => typed_data.hash()?,
 		};
 
 		let (recoverable_sig, _recovery_id) = self.as_signature()?;
 		let verify_key = recoverable_sig
 			.recover_verifying_key_from_digest_bytes(message_hash.as_ref().into())?;
 
-		let public_key = K256PublicKey::from(&verify_key);
-		let public_key = public_key.to_encoded_point(/* compress = */ false);
-		let public_key = public_key.as_bytes();
-		debug_assert_eq!(public_key[0], 0x04);
-		let hash = Keccak256::hash(&public_key[1..]);
-		Ok(Address::from_slice(&hash[12..]))
+		Ok(address_from_public_key(&K256PublicKey::from(&verify_key)))
+	}
+
+	/// Like [`recover`](Self::recover), but rejects signatures whose `s`
+	/// value is not in canonical low-S form (EIP-2), refusing malleable
+	/// signatures.
+	pub fn recover_strict<M>(&self, message: M) -> Result<Address, SignatureError>
+	where
+		M: Into<RecoveryMessage>,
+	{
+		if !self.is_low_s() {
+			return Err(SignatureError::InvalidS);
+		}
+
+		self.recover(message)
+	}
+
+	/// Returns `true` if `s` is in the lower half of the secp256k1 group
+	/// order, as required by Ethereum consensus (EIP-2).
+	pub fn is_low_s(&self) -> bool {
+		self.s <= SECP256K1_N_HALF
+	}
+
+	/// Converts `self` into canonical low-S form in place, flipping the
+	/// recovery parity encoded in `v` to compensate.
+	pub fn normalize_s(&mut self) {
+		if !self.is_low_s() {
+			self.s = SECP256K1_N - self.s;
+			self.v = flip_recovery_parity(self.v);
+		}
 	}
 
 	/// Retrieves the recovery signature.
@@ -154,6 +221,77 @@ impl Signature {
 	pub fn to_vec(&self) -> Vec<u8> {
 		self.into()
 	}
+
+	/// Serializes `self` into the 64-byte compact representation defined by
+	/// EIP-2098: `r` followed by `yParityAndS`, `s` with its top bit
+	/// replaced by the recovery parity. Only representable for signatures
+	/// already in low-S form; normalize with [`normalize_s`](Self::normalize_s)
+	/// first if needed.
+	pub fn to_compact(&self) -> Result<[u8; 64], SignatureError> {
+		if !self.is_low_s() {
+			return Err(SignatureError::InvalidS);
+		}
+		let parity = self.recovery_id()?.to_byte();
+
+		let mut compact = [0u8; 64];
+		self.r.to_big_endian(&mut compact[..32]);
+		self.s.to_big_endian(&mut compact[32..]);
+		compact[32] |= parity << 7;
+
+		Ok(compact)
+	}
+
+	/// Parses a 64-byte EIP-2098 compact signature, as produced by
+	/// [`to_compact`](Self::to_compact). Rejects inputs whose decoded `s`
+	/// would not be in canonical low-S form.
+	pub fn from_compact(bytes: &[u8; 64]) -> Result<Self, SignatureError> {
+		let r = U256::from_big_endian(&bytes[..32]);
+
+		let mut s_bytes = [0u8; 32];
+		s_bytes.copy_from_slice(&bytes[32..]);
+		let parity = s_bytes[0] >> 7;
+		s_bytes[0] &= 0x7f;
+		let s = U256::from_big_endian(&s_bytes);
+
+		if s > SECP256K1_N_HALF {
+			return Err(SignatureError::InvalidS);
+		}
+
+		Ok(Signature { r, s, v: parity as u64 + 27 })
+	}
+
+	/// Returns the EIP-155 chain id encoded in `v`, or `None` if `v` is a
+	/// raw (non-EIP-155) recovery value, i.e. `0`, `1`, `27` or `28`.
+	pub fn chain_id(&self) -> Option<u64> {
+		match self.v {
+			0 | 1 | 27 | 28 => None,
+			v if v >= 35 => Some((v - 35) / 2),
+			_ => None,
+		}
+	}
+}
+
+/// Computes the EIP-155 `v` value for `recovery_id` on `chain_id`, i.e.
+/// `recovery_id + 35 + chain_id * 2`.
+pub fn to_eip155_v(recovery_id: u8, chain_id: u64) -> u64 {
+	recovery_id as u64 + 35 + chain_id * 2
+}
+
+/// Flips the recovery parity encoded in `v`, preserving the EIP-155 chain
+/// id (if any).
+fn flip_recovery_parity(v: u64) -> u64 {
+	match v {
+		0 => 1,
+		1 => 0,
+		27 => 28,
+		28 => 27,
+		v if v >= 35 => {
+			let chain_id = (v - 35) / 2;
+			let recovery_id = 1 - normalize_recovery_id(v);
+			to_eip155_v(recovery_id, chain_id)
+		}
+		v => v,
+	}
 }
 
 fn normalize_recovery_id(v: u64) -> u8 {
@@ -167,6 +305,69 @@ fn normalize_recovery_id(v: u64) -> u8 {
 	}
 }
 
+/// Derives the Ethereum address owning `public_key`, i.e. the low 20 bytes of
+/// the keccak256 hash of the uncompressed public key (minus the `0x04`
+/// prefix byte).
+fn address_from_public_key(public_key: &K256PublicKey) -> Address {
+	let public_key = public_key.to_encoded_point(/* compress = */ false);
+	let public_key = public_key.as_bytes();
+	debug_assert_eq!(public_key[0], 0x04);
+	let hash = Keccak256::hash(&public_key[1..]);
+	Address::from_slice(&hash[12..])
+}
+
+/// A secp256k1 private key, used to produce [`Signature`]s.
+pub struct PrivateKey(K256SigningKey);
+
+impl PrivateKey {
+	/// Parses a private key from its big-endian byte encoding.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, SignatureError> {
+		Ok(Self(K256SigningKey::from_bytes(bytes)?))
+	}
+
+	/// Generates a new, random private key.
+	#[cfg(feature = "std")]
+	pub fn random() -> Self {
+		Self(K256SigningKey::random(&mut OsRng))
+	}
+
+	/// Returns the Ethereum address corresponding to this private key's
+	/// public key.
+	pub fn address(&self) -> Address {
+		address_from_public_key(&K256PublicKey::from(self.0.verifying_key()))
+	}
+
+	/// Signs `message`, producing a [`Signature`] in 'Electrum' notation
+	/// (`v` is `27` or `28`).
+	pub fn sign<M>(&self, message: M) -> Result<Signature, SignatureError>
+	where
+		M: Into<RecoveryMessage>,
+	{
+		let message = message.into();
+		let message_hash = match message {
+			RecoveryMessage::Data(ref message) => hash_message(message),
+			RecoveryMessage::Hash(hash) => hash,
+			RecoveryMessage::TypedData(ref typed_data) => typed_data.hash()?,
+		};
+
+		let recoverable_sig = RecoverableSignature::from_digest_bytes_trial_recovery(
+			&self.0,
+			message_hash.as_ref().into(),
+		)?;
+
+		let mut r_bytes = [0u8; 32];
+		let mut s_bytes = [0u8; 32];
+		r_bytes.copy_from_slice(&recoverable_sig.r().to_bytes());
+		s_bytes.copy_from_slice(&recoverable_sig.s().to_bytes());
+
+		Ok(Signature {
+			r: U256::from_big_endian(&r_bytes),
+			s: U256::from_big_endian(&s_bytes),
+			v: recoverable_sig.recovery_id().to_byte() as u64 + 27,
+		})
+	}
+}
+
 impl From<sp_core::ecdsa::Signature> for Signature {
 	fn from(src: sp_core::ecdsa::Signature) -> Self {
 		let raw_src = src.0;
@@ -296,10 +497,341 @@ impl From<ethers_core::types::Signature> for Signature {
 	}
 }
 
+/// The `EIP712Domain` struct referenced by every [`TypedData`] message.
+///
+/// All fields are optional: only the ones that are `Some` take part in the
+/// domain type and its `encodeData`, per the EIP-712 spec.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Eip712Domain {
+	/// The user readable name of signing domain
+	pub name: Option<String>,
+	/// The current major version of the signing domain
+	pub version: Option<String>,
+	/// The EIP-155 chain id
+	pub chain_id: Option<U256>,
+	/// The address of the contract that will verify the signature
+	pub verifying_contract: Option<Address>,
+	/// A disambiguating salt for the protocol
+	pub salt: Option<[u8; 32]>,
+}
+
+/// A single member of an EIP-712 struct type, e.g. `uint256 amount`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Eip712FieldType {
+	/// The member's name, e.g. `amount`
+	pub name: String,
+	/// The member's EIP-712 type, e.g. `uint256` or `Person[]`
+	pub r#type: String,
+}
+
+/// A value in an EIP-712 message, typed according to its declared
+/// [`Eip712FieldType::type`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Eip712Value {
+	/// `string`
+	String(String),
+	/// `bytes`, or `bytesN` when the declared type says so
+	Bytes(Vec<u8>),
+	/// Any `uintN`/`intN`
+	Uint(U256),
+	/// `bool`
+	Bool(bool),
+	/// `address`
+	Address(Address),
+	/// Any array type, e.g. `uint256[]` or `Person[]`
+	Array(Vec<Eip712Value>),
+	/// A nested struct, keyed by member name
+	Struct(BTreeMap<String, Eip712Value>),
+}
+
+/// An EIP-712 typed structured-data message, ready to be hashed and
+/// recovered against via [`RecoveryMessage::TypedData`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypedData {
+	/// The `EIP712Domain` this message is signed under
+	pub domain: Eip712Domain,
+	/// All struct types referenced by `primary_type`, keyed by type name
+	pub types: BTreeMap<String, Vec<Eip712FieldType>>,
+	/// The name of the struct type of `message`
+	pub primary_type: String,
+	/// The message to hash, whose shape must match `types[primary_type]`
+	pub message: Eip712Value,
+}
+
+impl TypedData {
+	/// Computes the EIP-712 digest for this message:
+	/// `keccak256(0x19 0x01 || domainSeparator || hashStruct(message))`.
+	pub fn hash(&self) -> Result<H256, SignatureError> {
+		let domain_separator = self.domain.separator()?;
+		let message_hash = hash_struct(&self.primary_type, &self.types, &self.message)?;
+
+		let mut digest_input = Vec::with_capacity(2 + 32 + 32);
+		digest_input.extend_from_slice(&[0x19, 0x01]);
+		digest_input.extend_from_slice(domain_separator.as_ref());
+		digest_input.extend_from_slice(message_hash.as_ref());
+
+		Ok(Keccak256::hash(&digest_input))
+	}
+}
+
+impl Eip712Domain {
+	/// The implicit `EIP712Domain` type and value, containing only the
+	/// members that are actually set.
+	fn type_and_value(&self) -> (Vec<Eip712FieldType>, Eip712Value) {
+		let mut fields = Vec::new();
+		let mut values = BTreeMap::new();
+
+		let mut push = |name: &str, ty: &str, value: Eip712Value| {
+			fields.push(Eip712FieldType { name: name.to_string(), r#type: ty.to_string() });
+			values.insert(name.to_string(), value);
+		};
+
+		if let Some(ref name) = self.name {
+			push("name", "string", Eip712Value::String(name.clone()));
+		}
+		if let Some(ref version) = self.version {
+			push("version", "string", Eip712Value::String(version.clone()));
+		}
+		if let Some(chain_id) = self.chain_id {
+			push("chainId", "uint256", Eip712Value::Uint(chain_id));
+		}
+		if let Some(verifying_contract) = self.verifying_contract {
+			push(
+				"verifyingContract",
+				"address",
+				Eip712Value::Address(verifying_contract),
+			);
+		}
+		if let Some(salt) = self.salt {
+			push("salt", "bytes32", Eip712Value::Bytes(salt.to_vec()));
+		}
+
+		(fields, Eip712Value::Struct(values))
+	}
+
+	/// The EIP-712 domain separator: `hashStruct(EIP712Domain{...})`.
+	pub fn separator(&self) -> Result<H256, SignatureError> {
+		let (fields, value) = self.type_and_value();
+		let mut types = BTreeMap::new();
+		types.insert("EIP712Domain".to_string(), fields);
+
+		hash_struct("EIP712Domain", &types, &value)
+	}
+}
+
+type Eip712Types = BTreeMap<String, Vec<Eip712FieldType>>;
+
+/// `hashStruct(s) = keccak256(typeHash || encodeData(s))`.
+fn hash_struct(
+	primary_type: &str,
+	types: &Eip712Types,
+	value: &Eip712Value,
+) -> Result<H256, SignatureError> {
+	let mut input = Vec::new();
+	input.extend_from_slice(type_hash(primary_type, types)?.as_ref());
+	input.extend_from_slice(&encode_data(primary_type, types, value)?);
+	Ok(Keccak256::hash(&input))
+}
+
+/// `typeHash = keccak256(encodeType(s))`.
+fn type_hash(primary_type: &str, types: &Eip712Types) -> Result<H256, SignatureError> {
+	Ok(Keccak256::hash(encode_type(primary_type, types)?.as_bytes()))
+}
+
+/// Builds the canonical `Name(type1 field1,type2 field2,...)` type string
+/// for `primary_type`, followed by the same for every struct type it
+/// depends on (transitively), sorted alphabetically, per EIP-712.
+fn encode_type(primary_type: &str, types: &Eip712Types) -> Result<String, SignatureError> {
+	let mut dependencies = find_type_dependencies(primary_type, types, &mut Vec::new());
+	dependencies.sort();
+
+	let mut encoded = String::new();
+	encoded.push_str(&encode_type_fields(primary_type, types)?);
+	for dependency in dependencies {
+		if dependency != primary_type {
+			encoded.push_str(&encode_type_fields(&dependency, types)?);
+		}
+	}
+
+	Ok(encoded)
+}
+
+fn encode_type_fields(type_name: &str, types: &Eip712Types) -> Result<String, SignatureError> {
+	let fields = types.get(type_name).ok_or_else(|| {
+		SignatureError::InvalidTypedData(format!("undeclared type `{type_name}`"))
+	})?;
+
+	let members = fields
+		.iter()
+		.map(|field| format!("{} {}", field.r#type, field.name))
+		.collect::<Vec<_>>()
+		.join(",");
+
+	Ok(format!("{type_name}({members})"))
+}
+
+/// Collects the names of every struct type `primary_type` depends on
+/// (including itself), by walking its fields' types.
+fn find_type_dependencies(
+	primary_type: &str,
+	types: &Eip712Types,
+	found: &mut Vec<String>,
+) -> Vec<String> {
+	if found.iter().any(|t| t == primary_type) {
+		return found.clone();
+	}
+	let fields = match types.get(primary_type) {
+		Some(fields) => fields,
+		None => return found.clone(),
+	};
+
+	found.push(primary_type.to_string());
+	for field in fields {
+		let element_type = strip_all_array_suffixes(&field.r#type);
+		if types.contains_key(element_type) {
+			find_type_dependencies(element_type, types, found);
+		}
+	}
+
+	found.clone()
+}
+
+/// Strips a single trailing `[...]` group (e.g. `Person[][]` -> `Person[]`),
+/// peeling off one array dimension at a time.
+fn strip_array_suffix(type_name: &str) -> &str {
+	match type_name.rfind('[') {
+		Some(index) => &type_name[..index],
+		None => type_name,
+	}
+}
+
+/// Strips every trailing `[...]` group (e.g. `Person[][]` -> `Person`), so
+/// multi-dimensional struct arrays are still recognized as a dependency on
+/// their element type.
+fn strip_all_array_suffixes(type_name: &str) -> &str {
+	let mut stripped = type_name;
+	while stripped.ends_with(']') {
+		stripped = strip_array_suffix(stripped);
+	}
+	stripped
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+	let mut word = [0u8; 32];
+	word.copy_from_slice(Keccak256::hash(data).as_ref());
+	word
+}
+
+/// `encodeData(s)`: concatenates each field's 32-byte ABI encoding.
+fn encode_data(
+	primary_type: &str,
+	types: &Eip712Types,
+	value: &Eip712Value,
+) -> Result<Vec<u8>, SignatureError> {
+	let fields = types.get(primary_type).ok_or_else(|| {
+		SignatureError::InvalidTypedData(format!("undeclared type `{primary_type}`"))
+	})?;
+	let members = match value {
+		Eip712Value::Struct(members) => members,
+		_ => {
+			return Err(SignatureError::InvalidTypedData(format!(
+				"expected a `{primary_type}` struct value"
+			)))
+		}
+	};
+
+	let mut encoded = Vec::with_capacity(32 * fields.len());
+	for field in fields {
+		let member_value = members.get(&field.name).ok_or_else(|| {
+			SignatureError::InvalidTypedData(format!("missing field `{}`", field.name))
+		})?;
+		encoded.extend_from_slice(&encode_value(&field.r#type, types, member_value)?);
+	}
+
+	Ok(encoded)
+}
+
+/// Encodes a single field's value to its 32-byte ABI word, per EIP-712's
+/// `encodeData` rules.
+fn encode_value(
+	field_type: &str,
+	types: &Eip712Types,
+	value: &Eip712Value,
+) -> Result<[u8; 32], SignatureError> {
+	if field_type.ends_with(']') {
+		let element_type = strip_array_suffix(field_type);
+		let elements = match value {
+			Eip712Value::Array(elements) => elements,
+			_ => {
+				return Err(SignatureError::InvalidTypedData(format!(
+					"expected an array for `{field_type}`"
+				)))
+			}
+		};
+
+		let mut packed = Vec::with_capacity(32 * elements.len());
+		for element in elements {
+			packed.extend_from_slice(&encode_value(element_type, types, element)?);
+		}
+		return Ok(keccak256(&packed));
+	}
+
+	if types.contains_key(field_type) {
+		let mut word = [0u8; 32];
+		word.copy_from_slice(hash_struct(field_type, types, value)?.as_ref());
+		return Ok(word);
+	}
+
+	match (field_type, value) {
+		("string", Eip712Value::String(s)) => Ok(keccak256(s.as_bytes())),
+		("bytes", Eip712Value::Bytes(b)) => Ok(keccak256(b)),
+		("bool", Eip712Value::Bool(b)) => {
+			let mut word = [0u8; 32];
+			word[31] = *b as u8;
+			Ok(word)
+		}
+		("address", Eip712Value::Address(address)) => {
+			let mut word = [0u8; 32];
+			word[12..].copy_from_slice(address.as_ref());
+			Ok(word)
+		}
+		(ty, Eip712Value::Uint(n)) if ty.starts_with("uint") || ty.starts_with("int") => {
+			let mut word = [0u8; 32];
+			n.to_big_endian(&mut word);
+			Ok(word)
+		}
+		(ty, Eip712Value::Bytes(b)) if ty.starts_with("bytes") => {
+			if b.len() > 32 {
+				return Err(SignatureError::InvalidTypedData(format!(
+					"`{ty}` value longer than 32 bytes"
+				)));
+			}
+			let mut word = [0u8; 32];
+			word[..b.len()].copy_from_slice(b);
+			Ok(word)
+		}
+		(ty, _) => Err(SignatureError::InvalidTypedData(format!(
+			"value does not match declared type `{ty}`"
+		))),
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	// Well-known test private key (Hardhat/Anvil account #0).
+	fn test_private_key() -> PrivateKey {
+		let key_bytes =
+			hex::decode("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80")
+				.expect("valid hex");
+		PrivateKey::from_bytes(&key_bytes).expect("valid private key")
+	}
+
 	#[test]
 	fn recover_web3_signature() {
 		// test vector taken from:
@@ -325,4 +857,249 @@ mod tests {
 
 		assert_eq!(s1, s2);
 	}
+
+	#[test]
+	fn sign_and_recover_round_trip() {
+		let private_key = test_private_key();
+
+		let signature = private_key.sign("Some data").expect("signing should succeed");
+
+		assert_eq!(signature.recover("Some data").unwrap(), private_key.address());
+	}
+
+	#[test]
+	fn eip155_v_round_trip() {
+		let private_key = test_private_key();
+
+		let mut signature = private_key.sign("Some data").expect("signing should succeed");
+		let recovery_id = normalize_recovery_id(signature.v);
+		let chain_id = 1337;
+
+		signature.v = to_eip155_v(recovery_id, chain_id);
+
+		assert_eq!(signature.chain_id(), Some(chain_id));
+		assert_eq!(signature.recover("Some data").unwrap(), private_key.address());
+	}
+
+	#[test]
+	fn chain_id_is_none_for_raw_v() {
+		for v in [0, 1, 27, 28] {
+			assert_eq!(Signature { r: U256::zero(), s: U256::zero(), v }.chain_id(), None);
+		}
+	}
+
+	#[test]
+	fn normalize_s_rejects_malleability() {
+		let private_key = test_private_key();
+		let signature = private_key.sign("Some data").expect("signing should succeed");
+		assert!(signature.is_low_s());
+
+		// Flip to the malleable, high-S counterpart signature.
+		let mut malleable = signature.clone();
+		malleable.s = SECP256K1_N - malleable.s;
+		malleable.v = flip_recovery_parity(malleable.v);
+		assert!(!malleable.is_low_s());
+
+		// Both forms recover to the same address ...
+		assert_eq!(
+			malleable.recover("Some data").unwrap(),
+			signature.recover("Some data").unwrap()
+		);
+		// ... but only the canonical form passes strict recovery.
+		assert!(signature.recover_strict("Some data").is_ok());
+		assert!(matches!(
+			malleable.recover_strict("Some data"),
+			Err(SignatureError::InvalidS)
+		));
+
+		// Normalizing the malleable signature restores the original.
+		malleable.normalize_s();
+		assert_eq!(malleable, signature);
+	}
+
+	#[test]
+	fn compact_signature_round_trip() {
+		let private_key = test_private_key();
+		let signature = private_key.sign("Some data").expect("signing should succeed");
+
+		let compact = signature.to_compact().expect("low-s signature is compactable");
+		let decoded = Signature::from_compact(&compact).expect("valid compact signature");
+
+		assert_eq!(decoded, signature);
+		assert_eq!(decoded.recover("Some data").unwrap(), private_key.address());
+	}
+
+	#[test]
+	fn from_compact_rejects_non_canonical_s() {
+		let mut compact = [0u8; 64];
+		// s = n/2 + 1, one past the canonical threshold.
+		(SECP256K1_N_HALF + U256::one()).to_big_endian(&mut compact[32..]);
+
+		assert!(matches!(
+			Signature::from_compact(&compact),
+			Err(SignatureError::InvalidS)
+		));
+	}
+
+	// The canonical `Mail` example from the EIP-712 specification.
+	fn mail_typed_data() -> TypedData {
+		let mut types = BTreeMap::new();
+		types.insert(
+			"Person".to_string(),
+			vec![
+				Eip712FieldType { name: "name".to_string(), r#type: "string".to_string() },
+				Eip712FieldType { name: "wallet".to_string(), r#type: "address".to_string() },
+			],
+		);
+		types.insert(
+			"Mail".to_string(),
+			vec![
+				Eip712FieldType { name: "from".to_string(), r#type: "Person".to_string() },
+				Eip712FieldType { name: "to".to_string(), r#type: "Person".to_string() },
+				Eip712FieldType { name: "contents".to_string(), r#type: "string".to_string() },
+			],
+		);
+
+		let person = |name: &str, wallet: &str| {
+			let mut fields = BTreeMap::new();
+			fields.insert("name".to_string(), Eip712Value::String(name.to_string()));
+			fields.insert(
+				"wallet".to_string(),
+				Eip712Value::Address(Address::from_str(wallet).unwrap()),
+			);
+			Eip712Value::Struct(fields)
+		};
+
+		let mut message = BTreeMap::new();
+		message.insert("from".to_string(), person("Cow", "CD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"));
+		message.insert("to".to_string(), person("Bob", "bBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"));
+		message.insert(
+			"contents".to_string(),
+			Eip712Value::String("Hello, Bob!".to_string()),
+		);
+
+		TypedData {
+			domain: Eip712Domain {
+				name: Some("Ether Mail".to_string()),
+				version: Some("1".to_string()),
+				chain_id: Some(U256::from(1u64)),
+				verifying_contract: Some(
+					Address::from_str("CcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC").unwrap(),
+				),
+				salt: None,
+			},
+			types,
+			primary_type: "Mail".to_string(),
+			message: Eip712Value::Struct(message),
+		}
+	}
+
+	#[test]
+	fn eip712_encode_type_includes_sorted_dependencies() {
+		let typed_data = mail_typed_data();
+		assert_eq!(
+			encode_type("Mail", &typed_data.types).unwrap(),
+			"Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+		);
+	}
+
+	#[test]
+	fn eip712_encode_type_includes_multi_dimensional_array_dependencies() {
+		let mut types = Eip712Types::new();
+		types.insert(
+			"Group".to_string(),
+			vec![Eip712FieldType { name: "members".to_string(), r#type: "Person[][]".to_string() }],
+		);
+		types.insert(
+			"Person".to_string(),
+			vec![Eip712FieldType { name: "name".to_string(), r#type: "string".to_string() }],
+		);
+
+		assert_eq!(
+			encode_type("Group", &types).unwrap(),
+			"Group(Person[][] members)Person(string name)"
+		);
+	}
+
+	#[test]
+	fn eip712_sign_and_recover_with_nested_array_field() {
+		let mut types = BTreeMap::new();
+		types.insert(
+			"Person".to_string(),
+			vec![Eip712FieldType { name: "name".to_string(), r#type: "string".to_string() }],
+		);
+		types.insert(
+			"Group".to_string(),
+			vec![Eip712FieldType { name: "members".to_string(), r#type: "Person[][]".to_string() }],
+		);
+
+		let person = |name: &str| {
+			let mut fields = BTreeMap::new();
+			fields.insert("name".to_string(), Eip712Value::String(name.to_string()));
+			Eip712Value::Struct(fields)
+		};
+
+		let mut message = BTreeMap::new();
+		message.insert(
+			"members".to_string(),
+			Eip712Value::Array(vec![
+				Eip712Value::Array(vec![person("Alice"), person("Bob")]),
+				Eip712Value::Array(vec![person("Carol")]),
+			]),
+		);
+
+		let typed_data = TypedData {
+			domain: Eip712Domain { name: Some("Nested Arrays".to_string()), ..Default::default() },
+			types,
+			primary_type: "Group".to_string(),
+			message: Eip712Value::Struct(message),
+		};
+
+		let private_key = test_private_key();
+		let signature = private_key
+			.sign(typed_data.clone())
+			.expect("signing should succeed");
+
+		assert_eq!(signature.recover(typed_data).unwrap(), private_key.address());
+	}
+
+	#[test]
+	fn eip712_hash_matches_reference_digest() {
+		let typed_data = mail_typed_data();
+
+		let domain_separator = typed_data.domain.separator().unwrap();
+		assert_eq!(
+			domain_separator,
+			H256::from_str("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+				.unwrap()
+		);
+
+		let message_hash =
+			hash_struct(&typed_data.primary_type, &typed_data.types, &typed_data.message).unwrap();
+		assert_eq!(
+			message_hash,
+			H256::from_str("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")
+				.unwrap()
+		);
+
+		assert_eq!(
+			typed_data.hash().unwrap(),
+			H256::from_str("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+				.unwrap()
+		);
+	}
+
+	#[test]
+	fn eip712_sign_and_recover_round_trip() {
+		let private_key = test_private_key();
+
+		let signature = private_key
+			.sign(mail_typed_data())
+			.expect("signing should succeed");
+
+		assert_eq!(
+			signature.recover(mail_typed_data()).unwrap(),
+			private_key.address()
+		);
+	}
 }